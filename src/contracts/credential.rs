@@ -1,8 +1,22 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Env, String, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN,
+    Env, Hash, String, Symbol, Vec,
+};
 
-const HASH_KEY: Symbol = symbol_short!("hash");
-const STATUS_KEY: Symbol = symbol_short!("status");
+contractmeta!(
+    key = "Description",
+    val = "On-chain issuer-signed credential registry with hash verification, expiry and an audit trail"
+);
+
+const ISSUER_KEY: Symbol = symbol_short!("issuer");
+// Nonce for issuer operations that have no per-credential nonce to use:
+// issuing a new credential, key rotation and schema migration.
+const ISSUER_NONCE_KEY: Symbol = symbol_short!("gnonce");
+const SCHEMA_VERSION_KEY: Symbol = symbol_short!("schemaver");
+
+const CREDENTIAL_TTL_THRESHOLD: u32 = 17_280; // ~1 day of ledgers
+const CREDENTIAL_TTL_EXTEND_TO: u32 = 518_400; // ~30 days of ledgers
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -10,6 +24,34 @@ pub enum CredentialStatus {
     Active,
     Revoked,
     Suspended,
+    Expired,
+}
+
+/// An issuer key, either a secp256r1 (WebAuthn/passkey) or secp256k1 key,
+/// letting off-chain issuers sign status changes without a Stellar account.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssuerPublicKey {
+    Secp256r1(BytesN<65>),
+    Secp256k1(BytesN<65>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Credential {
+    pub hash: BytesN<32>,
+    pub status: CredentialStatus,
+    pub expires_at: Option<u64>,
+    // Scoped per credential id so a signed call for one credential can't be
+    // invalidated by an update to a different one sharing the contract.
+    pub nonce: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Credential(BytesN<32>),
+    History(BytesN<32>),
 }
 
 #[contract]
@@ -17,26 +59,516 @@ pub struct CredentialContract;
 
 #[contractimpl]
 impl CredentialContract {
-    pub fn initialize(env: Env, hash: String, status: CredentialStatus) {
-        env.storage().instance().set(&HASH_KEY, &hash);
-        env.storage().instance().set(&STATUS_KEY, &status);
+    pub fn initialize(env: Env, issuer: IssuerPublicKey) {
+        if env.storage().instance().has(&ISSUER_KEY) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&ISSUER_KEY, &issuer);
+        env.storage().instance().set(&ISSUER_NONCE_KEY, &0u64);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &1u32);
+    }
+
+    /// Rotates the issuer key, requiring a signature from the *current*
+    /// issuer over `new_issuer (XDR) || nonce`, using the same
+    /// replay-protected scheme as `update_status`.
+    pub fn rotate_issuer(env: Env, new_issuer: IssuerPublicKey, signature: Bytes) {
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+        let nonce: u64 = env.storage().instance().get(&ISSUER_NONCE_KEY).unwrap_or(0);
+
+        let mut message = new_issuer.clone().to_xdr(&env);
+        message.append(&Bytes::from_array(&env, &nonce.to_be_bytes()));
+        Self::verify_issuer_signature(&env, &issuer, &message, &signature);
+
+        env.storage().instance().set(&ISSUER_KEY, &new_issuer);
+        env.storage().instance().set(&ISSUER_NONCE_KEY, &(nonce + 1));
+    }
+
+    /// Registers a new credential under `id`, requiring a signature from the
+    /// contract's issuer key over `id || hash || status (XDR) || nonce`.
+    /// Rejects `id`s that already have an entry instead of overwriting them.
+    pub fn issue(
+        env: Env,
+        id: BytesN<32>,
+        hash: BytesN<32>,
+        status: CredentialStatus,
+        expires_at: Option<u64>,
+        signature: Bytes,
+    ) {
+        let key = DataKey::Credential(id.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("credential already exists");
+        }
+
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+        let nonce: u64 = env.storage().instance().get(&ISSUER_NONCE_KEY).unwrap_or(0);
+
+        let mut message = id.clone().to_xdr(&env);
+        message.append(&hash.clone().to_xdr(&env));
+        message.append(&status.clone().to_xdr(&env));
+        message.append(&Bytes::from_array(&env, &nonce.to_be_bytes()));
+        Self::verify_issuer_signature(&env, &issuer, &message, &signature);
+
+        let credential = Credential { hash, status: status.clone(), expires_at, nonce: 0 };
+        env.storage().persistent().set(&key, &credential);
+        env.storage().persistent().extend_ttl(
+            &key,
+            CREDENTIAL_TTL_THRESHOLD,
+            CREDENTIAL_TTL_EXTEND_TO,
+        );
+        env.storage().instance().set(&ISSUER_NONCE_KEY, &(nonce + 1));
+        Self::append_history(&env, id, status);
+    }
+
+    pub fn get_hash(env: Env, id: BytesN<32>) -> BytesN<32> {
+        Self::load(&env, id).hash
+    }
+
+    pub fn get_status(env: Env, id: BytesN<32>) -> CredentialStatus {
+        let credential = Self::load(&env, id);
+        Self::effective_status(&env, &credential)
+    }
+
+    /// Updates a credential's status, requiring a signature from the
+    /// contract's issuer key over `id || hash || new_status (XDR) || nonce`.
+    /// The nonce is persisted and incremented on success so a replayed
+    /// signature is rejected by the next call.
+    pub fn update_status(
+        env: Env,
+        id: BytesN<32>,
+        new_status: CredentialStatus,
+        expected_schema_version: u32,
+        signature: Bytes,
+    ) {
+        let schema_version: u32 = env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1);
+        if expected_schema_version != schema_version {
+            panic!("schema version mismatch");
+        }
+
+        let key = DataKey::Credential(id.clone());
+        let mut credential: Credential = env.storage().persistent().get(&key).unwrap();
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+
+        let mut message = id.clone().to_xdr(&env);
+        message.append(&credential.hash.clone().to_xdr(&env));
+        message.append(&new_status.clone().to_xdr(&env));
+        message.append(&Bytes::from_array(&env, &credential.nonce.to_be_bytes()));
+        Self::verify_issuer_signature(&env, &issuer, &message, &signature);
+
+        let old_status = credential.status.clone();
+        credential.status = new_status.clone();
+        credential.nonce += 1;
+        env.storage().persistent().set(&key, &credential);
+        env.storage().persistent().extend_ttl(
+            &key,
+            CREDENTIAL_TTL_THRESHOLD,
+            CREDENTIAL_TTL_EXTEND_TO,
+        );
+
+        env.events().publish(
+            (symbol_short!("status"), id.clone()),
+            (old_status, new_status.clone(), env.ledger().timestamp()),
+        );
+        Self::append_history(&env, id, new_status);
+    }
+
+    /// Convenience wrapper around `update_status` for the common case of
+    /// revoking a credential.
+    pub fn revoke(env: Env, id: BytesN<32>, expected_schema_version: u32, signature: Bytes) {
+        Self::update_status(
+            env,
+            id,
+            CredentialStatus::Revoked,
+            expected_schema_version,
+            signature,
+        );
+    }
+
+    pub fn get_credential_info(env: Env, id: BytesN<32>) -> (BytesN<32>, CredentialStatus) {
+        let credential = Self::load(&env, id);
+        let status = Self::effective_status(&env, &credential);
+        (credential.hash, status)
+    }
+
+    /// Hashes `document` and checks it against the stored credential hash,
+    /// also rejecting anything that isn't `Active` so a verifier contract
+    /// can trust a single boolean from a cross-contract call.
+    pub fn verify(env: Env, id: BytesN<32>, document: Bytes) -> bool {
+        let credential = Self::load(&env, id);
+        if Self::effective_status(&env, &credential) != CredentialStatus::Active {
+            return false;
+        }
+        let digest: BytesN<32> = env.crypto().sha256(&document).into();
+        digest == credential.hash
+    }
+
+    /// Extends (or sets) a credential's expiry, requiring a signature from
+    /// the contract's issuer key over `id || new_expires_at || nonce`, using
+    /// the same replay-protected scheme as `update_status`.
+    pub fn extend_validity(env: Env, id: BytesN<32>, new_expires_at: u64, signature: Bytes) {
+        let key = DataKey::Credential(id.clone());
+        let mut credential: Credential = env.storage().persistent().get(&key).unwrap();
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+
+        let mut message = id.to_xdr(&env);
+        message.append(&new_expires_at.to_xdr(&env));
+        message.append(&Bytes::from_array(&env, &credential.nonce.to_be_bytes()));
+        Self::verify_issuer_signature(&env, &issuer, &message, &signature);
+
+        credential.expires_at = Some(new_expires_at);
+        credential.nonce += 1;
+        env.storage().persistent().set(&key, &credential);
+        env.storage().persistent().extend_ttl(
+            &key,
+            CREDENTIAL_TTL_THRESHOLD,
+            CREDENTIAL_TTL_EXTEND_TO,
+        );
+    }
+
+    /// Machine-readable description of the credential type, issuer key
+    /// scheme and hashing algorithm this deployment uses, so wallets and
+    /// verifiers can discover what a deployed contract represents.
+    pub fn get_schema(env: Env) -> String {
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+        let description = match issuer {
+            IssuerPublicKey::Secp256r1(_) => {
+                "credential_type=generic;hash_algorithm=sha256;issuer_scheme=secp256r1"
+            }
+            IssuerPublicKey::Secp256k1(_) => {
+                "credential_type=generic;hash_algorithm=sha256;issuer_scheme=secp256k1"
+            }
+        };
+        String::from_str(&env, description)
+    }
+
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1)
+    }
+
+    /// Bumps the schema version, requiring a signature from the issuer key
+    /// over `new_version || nonce`. Existing credentials are left untouched;
+    /// `update_status` starts rejecting calls built against the old version.
+    pub fn migrate_schema(env: Env, new_version: u32, signature: Bytes) {
+        let issuer: IssuerPublicKey = env.storage().instance().get(&ISSUER_KEY).unwrap();
+        let nonce: u64 = env.storage().instance().get(&ISSUER_NONCE_KEY).unwrap_or(0);
+
+        let mut message = new_version.to_xdr(&env);
+        message.append(&Bytes::from_array(&env, &nonce.to_be_bytes()));
+        Self::verify_issuer_signature(&env, &issuer, &message, &signature);
+
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &new_version);
+        env.storage().instance().set(&ISSUER_NONCE_KEY, &(nonce + 1));
+    }
+
+    fn verify_issuer_signature(
+        env: &Env,
+        issuer: &IssuerPublicKey,
+        message: &Bytes,
+        signature: &Bytes,
+    ) {
+        let digest: Hash<32> = env.crypto().sha256(message);
+        match issuer {
+            IssuerPublicKey::Secp256r1(pubkey) => {
+                let signature: BytesN<64> = signature
+                    .clone()
+                    .try_into()
+                    .unwrap_or_else(|_| panic!("invalid signature length"));
+                env.crypto().secp256r1_verify(pubkey, &digest, &signature);
+            }
+            IssuerPublicKey::Secp256k1(pubkey) => {
+                if signature.len() != 65 {
+                    panic!("invalid signature length");
+                }
+                let recovery_id = signature.get_unchecked(64) as u32;
+                let signature: BytesN<64> = signature
+                    .slice(0..64)
+                    .try_into()
+                    .unwrap_or_else(|_| panic!("invalid signature length"));
+                let recovered = env.crypto().secp256k1_recover(&digest, &signature, recovery_id);
+                if &recovered != pubkey {
+                    panic!("signature does not match issuer key");
+                }
+            }
+        }
+    }
+
+    fn effective_status(env: &Env, credential: &Credential) -> CredentialStatus {
+        if let Some(expires_at) = credential.expires_at {
+            if env.ledger().timestamp() >= expires_at {
+                return CredentialStatus::Expired;
+            }
+        }
+        credential.status.clone()
+    }
+
+    /// Returns every status transition recorded for `id`, oldest first,
+    /// paired with the ledger timestamp it happened at.
+    pub fn get_history(env: Env, id: BytesN<32>) -> Vec<(CredentialStatus, u64)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History(id))
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
-    pub fn get_hash(env: Env) -> String {
-        env.storage().instance().get(&HASH_KEY).unwrap()
+    fn load(env: &Env, id: BytesN<32>) -> Credential {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Credential(id))
+            .unwrap()
     }
 
-    pub fn get_status(env: Env) -> CredentialStatus {
-        env.storage().instance().get(&STATUS_KEY).unwrap()
+    fn append_history(env: &Env, id: BytesN<32>, status: CredentialStatus) {
+        let key = DataKey::History(id);
+        let mut history: Vec<(CredentialStatus, u64)> =
+            env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+        history.push_back((status, env.ledger().timestamp()));
+        env.storage().persistent().set(&key, &history);
+        env.storage().persistent().extend_ttl(
+            &key,
+            CREDENTIAL_TTL_THRESHOLD,
+            CREDENTIAL_TTL_EXTEND_TO,
+        );
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ecdsa::hazmat::PrehashSigner;
+    use soroban_sdk::testutils::Ledger as _;
+
+    // Matches the `id || hash || status (XDR) || nonce` scheme shared by
+    // `issue` and `update_status`.
+    fn status_digest(
+        env: &Env,
+        id: &BytesN<32>,
+        hash: &BytesN<32>,
+        status: &CredentialStatus,
+        nonce: u64,
+    ) -> [u8; 32] {
+        let mut message = id.clone().to_xdr(env);
+        message.append(&hash.clone().to_xdr(env));
+        message.append(&status.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        env.crypto().sha256(&message).into()
+    }
+
+    fn secp256r1_issuer(env: &Env) -> (p256::ecdsa::SigningKey, IssuerPublicKey) {
+        let signing_key = p256::ecdsa::SigningKey::random(&mut rand_core::OsRng);
+        let encoded = signing_key.verifying_key().to_encoded_point(false);
+        let public_key = BytesN::from_array(env, encoded.as_bytes().try_into().unwrap());
+        (signing_key, IssuerPublicKey::Secp256r1(public_key))
+    }
+
+    fn sign_secp256r1(env: &Env, signing_key: &p256::ecdsa::SigningKey, digest: [u8; 32]) -> Bytes {
+        let signature: p256::ecdsa::Signature = signing_key.sign_prehash(&digest).unwrap();
+        // The host only accepts low-S secp256r1 signatures.
+        let signature = signature.normalize_s().unwrap_or(signature);
+        Bytes::from_array(env, signature.to_bytes().as_slice().try_into().unwrap())
+    }
+
+    fn secp256k1_issuer(env: &Env) -> (k256::ecdsa::SigningKey, IssuerPublicKey) {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand_core::OsRng);
+        let encoded = signing_key.verifying_key().to_encoded_point(false);
+        let public_key = BytesN::from_array(env, encoded.as_bytes().try_into().unwrap());
+        (signing_key, IssuerPublicKey::Secp256k1(public_key))
+    }
+
+    fn sign_secp256k1(env: &Env, signing_key: &k256::ecdsa::SigningKey, digest: [u8; 32]) -> Bytes {
+        let (signature, recovery_id): (k256::ecdsa::Signature, k256::ecdsa::RecoveryId) =
+            signing_key.sign_prehash_recoverable(&digest).unwrap();
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(signature.to_bytes().as_slice());
+        bytes[64] = recovery_id.to_byte();
+        Bytes::from_array(env, &bytes)
+    }
+
+    fn setup(env: &Env) -> (BytesN<32>, p256::ecdsa::SigningKey, BytesN<32>) {
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (signing_key, issuer) = secp256r1_issuer(env);
+        let id = BytesN::from_array(env, &[7u8; 32]);
+        let hash = BytesN::from_array(env, &[9u8; 32]);
+
+        let client = CredentialContractClient::new(env, &contract_id);
+        client.initialize(&issuer);
+
+        let d = status_digest(env, &id, &hash, &CredentialStatus::Active, 0);
+        let signature = sign_secp256r1(env, &signing_key, d);
+        client.issue(&id, &hash, &CredentialStatus::Active, &None, &signature);
 
-    pub fn update_status(env: Env, new_status: CredentialStatus) {
-        env.storage().instance().set(&STATUS_KEY, &new_status);
+        (contract_id, signing_key, id)
     }
 
-    pub fn get_credential_info(env: Env) -> (String, CredentialStatus) {
-        let hash: String = env.storage().instance().get(&HASH_KEY).unwrap();
-        let status: CredentialStatus = env.storage().instance().get(&STATUS_KEY).unwrap();
-        (hash, status)
+    #[test]
+    fn update_status_with_valid_signature_applies_and_records_history() {
+        let env = Env::default();
+        let (contract_id, signing_key, id) = setup(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        let hash = client.get_hash(&id);
+
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Suspended, 0);
+        let signature = sign_secp256r1(&env, &signing_key, d);
+
+        client.update_status(&id, &CredentialStatus::Suspended, &1, &signature);
+
+        assert_eq!(client.get_status(&id), CredentialStatus::Suspended);
+        assert_eq!(client.get_history(&id).len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_status_rejects_invalid_signature() {
+        let env = Env::default();
+        let (contract_id, _signing_key, id) = setup(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+
+        let bogus_signature = Bytes::from_array(&env, &[0u8; 64]);
+        client.update_status(&id, &CredentialStatus::Suspended, &1, &bogus_signature);
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_status_rejects_replayed_signature() {
+        let env = Env::default();
+        let (contract_id, signing_key, id) = setup(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        let hash = client.get_hash(&id);
+
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Suspended, 0);
+        let signature = sign_secp256r1(&env, &signing_key, d);
+        client.update_status(&id, &CredentialStatus::Suspended, &1, &signature);
+
+        // Same signature again: the credential's nonce already advanced, so
+        // the digest it verifies against no longer matches.
+        client.update_status(&id, &CredentialStatus::Suspended, &1, &signature);
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_status_rejects_wrong_schema_version() {
+        let env = Env::default();
+        let (contract_id, signing_key, id) = setup(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        let hash = client.get_hash(&id);
+
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Suspended, 0);
+        let signature = sign_secp256r1(&env, &signing_key, d);
+
+        client.update_status(&id, &CredentialStatus::Suspended, &2, &signature);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn update_status_with_valid_secp256k1_signature_applies() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (signing_key, issuer) = secp256k1_issuer(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        client.initialize(&issuer);
+
+        let id = BytesN::from_array(&env, &[3u8; 32]);
+        let hash = BytesN::from_array(&env, &[4u8; 32]);
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Active, 0);
+        let signature = sign_secp256k1(&env, &signing_key, d);
+        client.issue(&id, &hash, &CredentialStatus::Active, &None, &signature);
+
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Revoked, 0);
+        let signature = sign_secp256k1(&env, &signing_key, d);
+        client.update_status(&id, &CredentialStatus::Revoked, &1, &signature);
+
+        assert_eq!(client.get_status(&id), CredentialStatus::Revoked);
+    }
+
+    #[test]
+    fn rotate_issuer_with_valid_signature_updates_key() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (old_signing_key, old_issuer) = secp256r1_issuer(&env);
+        let (_new_signing_key, new_issuer) = secp256r1_issuer(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        client.initialize(&old_issuer);
+
+        let mut message = new_issuer.clone().to_xdr(&env);
+        message.append(&Bytes::from_array(&env, &0u64.to_be_bytes()));
+        let d: [u8; 32] = env.crypto().sha256(&message).into();
+        let signature = sign_secp256r1(&env, &old_signing_key, d);
+
+        client.rotate_issuer(&new_issuer, &signature);
+
+        // Issuing with the old key now fails since the issuer key rotated
+        // (signed over the current contract-wide nonce, 1 after the rotation).
+        let id = BytesN::from_array(&env, &[5u8; 32]);
+        let hash = BytesN::from_array(&env, &[6u8; 32]);
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Active, 1);
+        let stale_signature = sign_secp256r1(&env, &old_signing_key, d);
+        let result = client.try_issue(&id, &hash, &CredentialStatus::Active, &None, &stale_signature);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn migrate_schema_with_valid_signature_bumps_version() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (signing_key, issuer) = secp256r1_issuer(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        client.initialize(&issuer);
+
+        let mut message = 2u32.to_xdr(&env);
+        message.append(&Bytes::from_array(&env, &0u64.to_be_bytes()));
+        let d: [u8; 32] = env.crypto().sha256(&message).into();
+        let signature = sign_secp256r1(&env, &signing_key, d);
+
+        client.migrate_schema(&2, &signature);
+
+        assert_eq!(client.get_schema_version(), 2);
+    }
+
+    #[test]
+    fn verify_succeeds_for_matching_document_on_active_credential() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (signing_key, issuer) = secp256r1_issuer(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        client.initialize(&issuer);
+
+        let id = BytesN::from_array(&env, &[8u8; 32]);
+        let document = Bytes::from_array(&env, &[42u8; 16]);
+        let hash: BytesN<32> = env.crypto().sha256(&document).into();
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Active, 0);
+        let signature = sign_secp256r1(&env, &signing_key, d);
+        client.issue(&id, &hash, &CredentialStatus::Active, &None, &signature);
+
+        assert!(client.verify(&id, &document));
+    }
+
+    #[test]
+    fn verify_fails_for_non_matching_document() {
+        let env = Env::default();
+        let (contract_id, _signing_key, id) = setup(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+
+        let document = Bytes::from_array(&env, &[42u8; 16]);
+        let hash: BytesN<32> = env.crypto().sha256(&document).into();
+        assert_ne!(hash, client.get_hash(&id));
+        assert!(!client.verify(&id, &document));
+    }
+
+    #[test]
+    fn expired_credential_reports_expired_status_and_fails_verify() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CredentialContract);
+        let (signing_key, issuer) = secp256r1_issuer(&env);
+        let client = CredentialContractClient::new(&env, &contract_id);
+        client.initialize(&issuer);
+
+        let id = BytesN::from_array(&env, &[1u8; 32]);
+        let document = Bytes::from_array(&env, &[2u8; 16]);
+        let hash: BytesN<32> = env.crypto().sha256(&document).into();
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let d = status_digest(&env, &id, &hash, &CredentialStatus::Active, 0);
+        let signature = sign_secp256r1(&env, &signing_key, d);
+        client.issue(&id, &hash, &CredentialStatus::Active, &Some(500), &signature);
+
+        assert_eq!(client.get_status(&id), CredentialStatus::Expired);
+        assert!(!client.verify(&id, &document));
+    }
+}